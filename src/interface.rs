@@ -1,5 +1,6 @@
 use super::UuidPoolError;
 
+use crate::registry::{default_store, UuidStore};
 use uuid::Uuid;
 
 pub const DEFAULT_UUID_BASE: u32 = 64;
@@ -17,40 +18,225 @@ pub fn reserve_with_base(context: &str, base: u32) -> Result<Uuid, UuidPoolError
 
 #[inline(always)]
 pub fn reserve_with(context: &str, base: u32, max_retries: usize) -> Result<Uuid, UuidPoolError> {
-    crate::registry::random_uuid(context, base, max_retries, 0)
+    reserve_with_in(default_store(), context, base, max_retries)
+}
+
+#[inline(always)]
+pub fn reserve_many(context: &str, base: u32, n: usize) -> Result<Vec<Uuid>, UuidPoolError> {
+    default_store().reserve_many(context, base, n, DEFAULT_MAX_RETRIES)
 }
 
 #[inline(always)]
 pub fn add(context: &str, uuid: Uuid) -> Result<(), UuidPoolError> {
-    crate::registry::add_uuid_to_pool(context, &uuid)
+    add_in(default_store(), context, uuid)
 }
 
 #[inline(always)]
 pub fn remove(context: &str, uuid: Uuid) -> Result<(), UuidPoolError> {
-    crate::registry::remove_uuid_from_pool(context, &uuid)
+    remove_in(default_store(), context, uuid)
 }
 
 #[inline(always)]
 pub fn try_remove(context: &str, uuid: Uuid) -> bool {
-    crate::registry::remove_uuid_from_pool(context, &uuid).is_ok()
+    try_remove_in(default_store(), context, uuid)
 }
 
 #[inline(always)]
 pub fn replace(context: &str, old_uuid: Uuid, new_uuid: Uuid) -> Result<(), UuidPoolError> {
-    crate::registry::replace_uuid_in_pool(context, &old_uuid, &new_uuid)
+    replace_in(default_store(), context, old_uuid, new_uuid)
 }
 
 #[inline(always)]
 pub fn get(context: &str) -> Result<Vec<(String, Uuid)>, UuidPoolError> {
-    crate::registry::get_context_uuids_from_pool(context)
+    get_in(default_store(), context)
 }
 
 #[inline(always)]
 pub fn clear_context(context: &str) -> Result<(), UuidPoolError> {
-    crate::registry::drain_context(context)
+    clear_context_in(default_store(), context)
 }
 
 #[inline(always)]
 pub fn clear_all() -> Result<(), UuidPoolError> {
-    crate::registry::drain_all_contexts()
+    clear_all_in(default_store())
+}
+
+// Store-parameterised variants. These mirror the functions above but operate
+// over any caller-supplied `UuidStore` rather than the process-global pool.
+
+#[inline(always)]
+pub fn reserve_in(store: &dyn UuidStore, context: &str) -> Result<Uuid, UuidPoolError> {
+    reserve_with_in(store, context, DEFAULT_UUID_BASE, DEFAULT_MAX_RETRIES)
+}
+
+#[inline(always)]
+pub fn reserve_with_base_in(
+    store: &dyn UuidStore,
+    context: &str,
+    base: u32,
+) -> Result<Uuid, UuidPoolError> {
+    reserve_with_in(store, context, base, DEFAULT_MAX_RETRIES)
+}
+
+#[inline(always)]
+pub fn reserve_with_in(
+    store: &dyn UuidStore,
+    context: &str,
+    base: u32,
+    max_retries: usize,
+) -> Result<Uuid, UuidPoolError> {
+    crate::registry::random_uuid(store, context, base, max_retries, 0)
+}
+
+#[inline(always)]
+pub fn add_in(store: &dyn UuidStore, context: &str, uuid: Uuid) -> Result<(), UuidPoolError> {
+    crate::registry::add_uuid_to_pool(store, context, &uuid)
+}
+
+#[inline(always)]
+pub fn remove_in(store: &dyn UuidStore, context: &str, uuid: Uuid) -> Result<(), UuidPoolError> {
+    crate::registry::remove_uuid_from_pool(store, context, &uuid)
+}
+
+#[inline(always)]
+pub fn try_remove_in(store: &dyn UuidStore, context: &str, uuid: Uuid) -> bool {
+    crate::registry::remove_uuid_from_pool(store, context, &uuid).is_ok()
+}
+
+#[inline(always)]
+pub fn replace_in(
+    store: &dyn UuidStore,
+    context: &str,
+    old_uuid: Uuid,
+    new_uuid: Uuid,
+) -> Result<(), UuidPoolError> {
+    crate::registry::replace_uuid_in_pool(store, context, &old_uuid, &new_uuid)
+}
+
+#[inline(always)]
+pub fn get_in(store: &dyn UuidStore, context: &str) -> Result<Vec<(String, Uuid)>, UuidPoolError> {
+    crate::registry::get_context_uuids_from_pool(store, context)
+}
+
+#[inline(always)]
+pub fn clear_context_in(store: &dyn UuidStore, context: &str) -> Result<(), UuidPoolError> {
+    crate::registry::drain_context(store, context)
+}
+
+#[inline(always)]
+pub fn clear_all_in(store: &dyn UuidStore) -> Result<(), UuidPoolError> {
+    crate::registry::drain_all_contexts(store)
+}
+
+// Read-only introspection of the process-global store, for exporting growth
+// metrics and detecting runaway contexts.
+
+/// List every context that currently holds at least one reserved UUID.
+#[inline(always)]
+pub fn list_contexts() -> Vec<String> {
+    default_store().list_contexts()
+}
+
+/// Number of UUIDs reserved under `context`.
+#[inline(always)]
+pub fn count(context: &str) -> usize {
+    default_store().count(context)
+}
+
+/// Total number of UUIDs reserved across every context.
+#[inline(always)]
+pub fn total_count() -> usize {
+    default_store().total_count()
+}
+
+/// Approximate heap used by the pool, in bytes.
+#[inline(always)]
+pub fn size_bytes() -> u64 {
+    default_store().size_bytes()
+}
+
+// Named UUID resolution against the process-global store. Names are scoped to
+// a context and kept consistent with the underlying set.
+
+/// Reserve a fresh UUID in `context` and bind `name` to it.
+#[inline(always)]
+pub fn reserve_named(context: &str, name: &str) -> Result<Uuid, UuidPoolError> {
+    default_store().reserve_named(context, name, DEFAULT_UUID_BASE, DEFAULT_MAX_RETRIES)
+}
+
+/// Resolve the UUID bound to `name` within `context`.
+#[inline(always)]
+pub fn get_by_name(context: &str, name: &str) -> Option<Uuid> {
+    default_store().get_by_name(context, name)
+}
+
+/// Resolve the name bound to `uuid` within `context`.
+#[inline(always)]
+pub fn name_of(context: &str, uuid: Uuid) -> Option<String> {
+    default_store().name_of(context, uuid)
+}
+
+/// Remove the UUID bound to `name` from both the name index and the pool.
+#[inline(always)]
+pub fn delete_by_name(context: &str, name: &str) -> Result<(), UuidPoolError> {
+    default_store().delete_by_name(context, name)
+}
+
+// Lease-based reservations against the process-global store. Available with the
+// `lease` feature. Expired entries are reclaimed lazily on access and by the
+// explicit sweep calls.
+
+/// Reserve a fresh UUID in `context` that expires after `ttl` unless renewed.
+#[cfg(feature = "lease")]
+#[inline(always)]
+pub fn reserve_with_lease(
+    context: &str,
+    ttl: std::time::Duration,
+) -> Result<Uuid, UuidPoolError> {
+    default_store().reserve_with_lease(context, ttl, DEFAULT_UUID_BASE, DEFAULT_MAX_RETRIES)
+}
+
+/// Extend the lease on `uuid` within `context` by `ttl` from now.
+#[cfg(feature = "lease")]
+#[inline(always)]
+pub fn renew(context: &str, uuid: Uuid, ttl: std::time::Duration) -> bool {
+    default_store().renew(context, uuid, ttl)
+}
+
+/// Reclaim every expired lease in `context`, returning the number dropped.
+#[cfg(feature = "lease")]
+#[inline(always)]
+pub fn sweep_expired(context: &str) -> usize {
+    default_store().sweep_expired(context)
+}
+
+/// Reclaim every expired lease across all contexts, returning the number dropped.
+#[cfg(feature = "lease")]
+#[inline(always)]
+pub fn sweep_all() -> usize {
+    default_store().sweep_all()
+}
+
+// Persistence operations against the process-global store. Available with the
+// `persistent` feature.
+
+/// Atomically copy the live global pool to `path`.
+#[cfg(feature = "persistent")]
+#[inline(always)]
+pub fn snapshot_to(path: impl AsRef<std::path::Path>) -> Result<(), UuidPoolError> {
+    default_store().snapshot(path)
+}
+
+/// Write the live global pool to `path` as re-importable line-delimited entries.
+#[cfg(feature = "persistent")]
+#[inline(always)]
+pub fn dump_to(path: impl AsRef<std::path::Path>) -> Result<(), UuidPoolError> {
+    default_store().dump(path)
+}
+
+/// Rehydrate the global pool from a file written by [`snapshot_to`] or [`dump_to`].
+#[cfg(feature = "persistent")]
+#[inline(always)]
+pub fn load_from(path: impl AsRef<std::path::Path>) -> Result<(), UuidPoolError> {
+    default_store().load(path)
 }
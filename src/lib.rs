@@ -2,6 +2,8 @@ mod registry;
 
 pub mod interface;
 
+pub use registry::{InMemoryStore, UuidStore};
+
 /// UUID generation code with thread-safe pool management.
 ///
 /// This module provides functions for generating unique UUIDs and tracking them in a thread-safe pool.
@@ -13,4 +15,11 @@ pub enum UuidPoolError {
     FailedToFindUuidInPoolError(String),
     #[error("Failed to set UUID in pool: {0}")]
     FailedToSetUuidInPoolError(String),
+    #[error("Name collision in pool: {0}")]
+    NameCollisionError(String),
+    #[error("Exhausted retry budget after reserving {succeeded} of {requested} UUIDs")]
+    PartialBatchError { requested: usize, succeeded: usize },
+    #[cfg(feature = "persistent")]
+    #[error("Failed to persist or load UUID pool: {0}")]
+    PersistenceError(String),
 }
\ No newline at end of file
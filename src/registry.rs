@@ -1,9 +1,12 @@
 use super::UuidPoolError;
 
-use rand::Rng;
+use rand::RngCore;
+use std::collections::HashMap;
 use std::sync::{Arc, OnceLock};
 #[cfg(not(feature = "concurrent"))]
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
+#[cfg(feature = "lease")]
+use std::time::{Duration, Instant};
 use uuid::Uuid;
 
 #[cfg(feature = "concurrent")]
@@ -25,141 +28,786 @@ enum GlobalUuidPool {
     Concurrent(ConcurrentPool),
 }
 
-// Thread-safe UUID pool using Mutex
-static GLOBAL_UUID_POOL: OnceLock<GlobalUuidPool> = OnceLock::new();
+/// Per-context bidirectional binding between human-readable names and UUIDs.
+#[derive(Default)]
+struct NameMaps {
+    by_name: HashMap<String, Uuid>,
+    by_uuid: HashMap<Uuid, String>,
+}
+
+// Name index mirrors the pool's representation under the `concurrent` flag.
+#[cfg(not(feature = "concurrent"))]
+type NameIndex = parking_lot::Mutex<HashMap<ContextKey, NameMaps>>;
+
+#[cfg(feature = "concurrent")]
+type NameIndex = DashMap<ContextKey, NameMaps>;
+
+// Lease deadlines mirror the pool's representation under the `concurrent` flag.
+// Only UUIDs reserved with a lease, or later given one by `renew`, appear here;
+// everything else never expires.
+#[cfg(all(feature = "lease", not(feature = "concurrent")))]
+type LeaseIndex = parking_lot::Mutex<HashMap<ContextKey, HashMap<Uuid, Instant>>>;
+
+#[cfg(all(feature = "lease", feature = "concurrent"))]
+type LeaseIndex = DashMap<ContextKey, HashMap<Uuid, Instant>>;
 
-fn global_pool() -> &'static GlobalUuidPool {
-    GLOBAL_UUID_POOL.get_or_init(|| {
+/// Backend responsible for tracking which UUIDs are reserved under a context.
+///
+/// The resolver logic (`random_uuid`, `add_uuid_to_pool`, ...) is written
+/// against this trait so the same crate works over the default in-memory pool
+/// or any caller-supplied backend. The default [`InMemoryStore`] keeps the
+/// historical global behaviour; other backends only have to honour the same
+/// per-context set semantics.
+pub trait UuidStore: Send + Sync {
+    /// Insert `uuid` into `context`, returning `true` if it was newly added.
+    fn insert(&self, context: &str, uuid: Uuid) -> bool;
+    /// Return `true` if `uuid` is currently reserved under `context`.
+    fn contains(&self, context: &str, uuid: Uuid) -> bool;
+    /// Remove `uuid` from `context`, returning `true` if it was present.
+    fn remove(&self, context: &str, uuid: Uuid) -> bool;
+    /// List every UUID reserved under `context`.
+    fn list(&self, context: &str) -> Vec<Uuid>;
+    /// Drop every UUID reserved under `context`.
+    fn clear_context(&self, context: &str);
+    /// Drop every UUID across every context.
+    fn clear_all(&self);
+    /// Move any human-readable name bound to `old` onto `new` within `context`.
+    ///
+    /// Called during a replace so a name survives the swap. Backends without a
+    /// name index leave this a no-op.
+    fn transfer_name(&self, _context: &str, _old: Uuid, _new: Uuid) {}
+}
+
+/// The crate's default [`UuidStore`]: the process-global in-memory pool.
+///
+/// Its internal representation follows the `concurrent` feature flag, using a
+/// `Mutex<HashMap>` single-threaded pool by default or a `DashMap` when the
+/// feature is enabled.
+pub struct InMemoryStore {
+    pool: GlobalUuidPool,
+    names: NameIndex,
+    #[cfg(feature = "lease")]
+    leases: LeaseIndex,
+}
+
+impl InMemoryStore {
+    /// Construct an empty in-memory store.
+    pub fn new() -> Self {
         #[cfg(not(feature = "concurrent"))]
         {
-            GlobalUuidPool::SingleThreaded(parking_lot::Mutex::new(HashMap::new()))
+            Self {
+                pool: GlobalUuidPool::SingleThreaded(parking_lot::Mutex::new(HashMap::new())),
+                names: parking_lot::Mutex::new(HashMap::new()),
+                #[cfg(feature = "lease")]
+                leases: parking_lot::Mutex::new(HashMap::new()),
+            }
         }
         #[cfg(feature = "concurrent")]
         {
-            GlobalUuidPool::Concurrent(DashMap::new())
+            Self {
+                pool: GlobalUuidPool::Concurrent(DashMap::new()),
+                names: DashMap::new(),
+                #[cfg(feature = "lease")]
+                leases: DashMap::new(),
+            }
         }
-    })
-}
+    }
 
-fn make_uuid_with_base(base: u32) -> Uuid {
-    let mut bytes = [0u8; 16];
-    bytes[0..4].copy_from_slice(&base.to_be_bytes());
-    for i in bytes.iter_mut().skip(4) {
-        *i = rand::rng().random_range(0..=255);
+    /// Drop any name binding pointing at `uuid` within `context`, keeping the
+    /// bidirectional index consistent when a UUID leaves the set.
+    fn unbind_uuid(&self, context: &str, uuid: Uuid) {
+        #[cfg(not(feature = "concurrent"))]
+        {
+            let mut map = self.names.lock();
+            if let Some(maps) = map.get_mut(context) {
+                if let Some(name) = maps.by_uuid.remove(&uuid) {
+                    maps.by_name.remove(&name);
+                }
+                if maps.by_name.is_empty() {
+                    map.remove(context);
+                }
+            }
+        }
+        #[cfg(feature = "concurrent")]
+        {
+            if let Some(mut maps) = self.names.get_mut(context) {
+                if let Some(name) = maps.by_uuid.remove(&uuid) {
+                    maps.by_name.remove(&name);
+                }
+            }
+        }
     }
-    Uuid::new_v8(bytes)
-}
 
-fn try_insert(context: &str, uuid: Uuid) -> bool {
-    match global_pool() {
+    /// Move the name bound to `old` (if any) onto `new` within `context`,
+    /// keeping both directions of the index consistent across a replace.
+    fn rebind_uuid(&self, context: &str, old: Uuid, new: Uuid) {
+        #[cfg(not(feature = "concurrent"))]
+        {
+            if let Some(maps) = self.names.lock().get_mut(context) {
+                if let Some(name) = maps.by_uuid.remove(&old) {
+                    maps.by_name.insert(name.clone(), new);
+                    maps.by_uuid.insert(new, name);
+                }
+            }
+        }
+        #[cfg(feature = "concurrent")]
+        {
+            if let Some(mut maps) = self.names.get_mut(context) {
+                if let Some(name) = maps.by_uuid.remove(&old) {
+                    maps.by_name.insert(name.clone(), new);
+                    maps.by_uuid.insert(new, name);
+                }
+            }
+        }
+    }
+
+    /// Reserve a fresh UUID under `context` and bind `name` to it.
+    ///
+    /// Fails with [`UuidPoolError::NameCollisionError`] if `name` is already
+    /// bound in `context`.
+    pub fn reserve_named(
+        &self,
+        context: &str,
+        name: &str,
+        base: u32,
+        max_retries: usize,
+    ) -> Result<Uuid, UuidPoolError> {
+        if self.get_by_name(context, name).is_some() {
+            return Err(UuidPoolError::NameCollisionError(format!(
+                "Name '{}' already bound in context '{}'",
+                name, context
+            )));
+        }
+
+        let uuid = random_uuid(self, context, base, max_retries, 0)?;
+        let key: ContextKey = Arc::from(context);
+
         #[cfg(not(feature = "concurrent"))]
-        GlobalUuidPool::SingleThreaded(pool) => {
-            let mut map = pool.lock();
-            let key: ContextKey = Arc::from(context);
-            map.entry(key).or_insert_with(HashSet::new).insert(uuid)
+        {
+            let mut map = self.names.lock();
+            let maps = map.entry(key).or_default();
+            maps.by_name.insert(name.to_string(), uuid);
+            maps.by_uuid.insert(uuid, name.to_string());
         }
         #[cfg(feature = "concurrent")]
-        GlobalUuidPool::Concurrent(pool) => {
-            let key: ContextKey = Arc::from(context);
+        {
+            let mut maps = self.names.entry(key).or_default();
+            maps.by_name.insert(name.to_string(), uuid);
+            maps.by_uuid.insert(uuid, name.to_string());
+        }
+
+        Ok(uuid)
+    }
 
-            let set_ref = pool.entry(key).or_insert_with(DashSet::new);
-            set_ref.insert(uuid)
+    /// Resolve the UUID bound to `name` within `context`, if any.
+    pub fn get_by_name(&self, context: &str, name: &str) -> Option<Uuid> {
+        #[cfg(not(feature = "concurrent"))]
+        {
+            self.names
+                .lock()
+                .get(context)
+                .and_then(|maps| maps.by_name.get(name).copied())
+        }
+        #[cfg(feature = "concurrent")]
+        {
+            self.names
+                .get(context)
+                .and_then(|maps| maps.by_name.get(name).copied())
+        }
+    }
+
+    /// Resolve the name bound to `uuid` within `context`, if any.
+    pub fn name_of(&self, context: &str, uuid: Uuid) -> Option<String> {
+        #[cfg(not(feature = "concurrent"))]
+        {
+            self.names
+                .lock()
+                .get(context)
+                .and_then(|maps| maps.by_uuid.get(&uuid).cloned())
+        }
+        #[cfg(feature = "concurrent")]
+        {
+            self.names
+                .get(context)
+                .and_then(|maps| maps.by_uuid.get(&uuid).cloned())
+        }
+    }
+
+    /// Remove the UUID bound to `name` from both the name index and the pool.
+    pub fn delete_by_name(&self, context: &str, name: &str) -> Result<(), UuidPoolError> {
+        let uuid = self.get_by_name(context, name).ok_or_else(|| {
+            UuidPoolError::FailedToFindUuidInPoolError(format!(
+                "No UUID bound to name '{}' in context '{}'",
+                name, context
+            ))
+        })?;
+
+        self.remove(context, uuid);
+        Ok(())
+    }
+
+    /// List every context that currently holds at least one reserved UUID.
+    pub fn list_contexts(&self) -> Vec<String> {
+        #[cfg(feature = "lease")]
+        self.sweep_all();
+        match &self.pool {
+            #[cfg(not(feature = "concurrent"))]
+            GlobalUuidPool::SingleThreaded(pool) => pool
+                .lock()
+                .iter()
+                .filter(|(_, set)| !set.is_empty())
+                .map(|(key, _)| key.to_string())
+                .collect(),
+            #[cfg(feature = "concurrent")]
+            GlobalUuidPool::Concurrent(pool) => pool
+                .iter()
+                .filter(|entry| !entry.value().is_empty())
+                .map(|entry| entry.key().to_string())
+                .collect(),
+        }
+    }
+
+    /// Number of UUIDs reserved under `context`.
+    pub fn count(&self, context: &str) -> usize {
+        #[cfg(feature = "lease")]
+        self.sweep_expired(context);
+        match &self.pool {
+            #[cfg(not(feature = "concurrent"))]
+            GlobalUuidPool::SingleThreaded(pool) => {
+                pool.lock().get(context).map(|set| set.len()).unwrap_or(0)
+            }
+            #[cfg(feature = "concurrent")]
+            GlobalUuidPool::Concurrent(pool) => pool
+                .get(context)
+                .map(|set_ref| set_ref.value().len())
+                .unwrap_or(0),
+        }
+    }
+
+    /// Total number of UUIDs reserved across every context.
+    pub fn total_count(&self) -> usize {
+        #[cfg(feature = "lease")]
+        self.sweep_all();
+        match &self.pool {
+            #[cfg(not(feature = "concurrent"))]
+            GlobalUuidPool::SingleThreaded(pool) => pool.lock().values().map(|set| set.len()).sum(),
+            #[cfg(feature = "concurrent")]
+            GlobalUuidPool::Concurrent(pool) => {
+                pool.iter().map(|entry| entry.value().len()).sum()
+            }
+        }
+    }
+
+    /// Reserve `n` unique UUIDs under `context` in one batch.
+    ///
+    /// On the single-threaded pool the whole batch is filled under a single
+    /// lock acquisition rather than re-locking per UUID. If the retry budget is
+    /// exhausted partway through, the UUIDs reserved so far remain and a
+    /// [`UuidPoolError::PartialBatchError`] reports how many succeeded.
+    pub fn reserve_many(
+        &self,
+        context: &str,
+        base: u32,
+        n: usize,
+        max_retries: usize,
+    ) -> Result<Vec<Uuid>, UuidPoolError> {
+        match &self.pool {
+            #[cfg(not(feature = "concurrent"))]
+            GlobalUuidPool::SingleThreaded(pool) => {
+                let mut map = pool.lock();
+                let key: ContextKey = Arc::from(context);
+                let set = map.entry(key).or_insert_with(HashSet::new);
+
+                let mut reserved = Vec::with_capacity(n);
+                for _ in 0..n {
+                    let mut inserted = false;
+                    for _ in 0..max_retries {
+                        let new_uuid = make_uuid_with_base(base);
+                        if set.insert(new_uuid) {
+                            reserved.push(new_uuid);
+                            inserted = true;
+                            break;
+                        }
+                    }
+                    if !inserted {
+                        return Err(UuidPoolError::PartialBatchError {
+                            requested: n,
+                            succeeded: reserved.len(),
+                        });
+                    }
+                }
+                Ok(reserved)
+            }
+            #[cfg(feature = "concurrent")]
+            GlobalUuidPool::Concurrent(_) => {
+                let mut reserved = Vec::with_capacity(n);
+                for _ in 0..n {
+                    match random_uuid(self, context, base, max_retries, 0) {
+                        Ok(new_uuid) => reserved.push(new_uuid),
+                        Err(_) => {
+                            return Err(UuidPoolError::PartialBatchError {
+                                requested: n,
+                                succeeded: reserved.len(),
+                            });
+                        }
+                    }
+                }
+                Ok(reserved)
+            }
+        }
+    }
+
+    /// Approximate heap used by the pool: `entries × 16 + context key lengths`.
+    pub fn size_bytes(&self) -> u64 {
+        #[cfg(feature = "lease")]
+        self.sweep_all();
+        match &self.pool {
+            #[cfg(not(feature = "concurrent"))]
+            GlobalUuidPool::SingleThreaded(pool) => pool
+                .lock()
+                .iter()
+                .map(|(key, set)| (set.len() * 16 + key.len()) as u64)
+                .sum(),
+            #[cfg(feature = "concurrent")]
+            GlobalUuidPool::Concurrent(pool) => pool
+                .iter()
+                .map(|entry| (entry.value().len() * 16 + entry.key().len()) as u64)
+                .sum(),
         }
     }
 }
 
-fn contains(context: &str, uuid: Uuid) -> bool {
-    match global_pool() {
+#[cfg(feature = "lease")]
+impl InMemoryStore {
+    /// Record a lease deadline for `uuid` within `context`.
+    fn set_lease(&self, context: &str, uuid: Uuid, deadline: Instant) {
+        let key: ContextKey = Arc::from(context);
+        #[cfg(not(feature = "concurrent"))]
+        {
+            self.leases.lock().entry(key).or_default().insert(uuid, deadline);
+        }
+        #[cfg(feature = "concurrent")]
+        {
+            self.leases.entry(key).or_default().insert(uuid, deadline);
+        }
+    }
+
+    /// Drop any lease deadline recorded for `uuid` within `context`.
+    fn drop_lease(&self, context: &str, uuid: Uuid) {
+        #[cfg(not(feature = "concurrent"))]
+        {
+            let mut map = self.leases.lock();
+            if let Some(deadlines) = map.get_mut(context) {
+                deadlines.remove(&uuid);
+                if deadlines.is_empty() {
+                    map.remove(context);
+                }
+            }
+        }
+        #[cfg(feature = "concurrent")]
+        {
+            if let Some(mut deadlines) = self.leases.get_mut(context) {
+                deadlines.remove(&uuid);
+            }
+        }
+    }
+
+    /// Return `true` if `uuid` carries a lease in `context` whose deadline has
+    /// already passed.
+    fn lease_expired(&self, context: &str, uuid: Uuid, now: Instant) -> bool {
         #[cfg(not(feature = "concurrent"))]
-        GlobalUuidPool::SingleThreaded(pool) => {
-            let map = pool.lock();
-            map.get(context)
-                .map(|set| set.contains(&uuid))
+        {
+            self.leases
+                .lock()
+                .get(context)
+                .and_then(|deadlines| deadlines.get(&uuid).map(|d| *d <= now))
                 .unwrap_or(false)
         }
         #[cfg(feature = "concurrent")]
-        GlobalUuidPool::Concurrent(pool) => pool
-            .get(context)
-            .map(|set_ref| set_ref.value().contains(&uuid))
-            .unwrap_or(false),
+        {
+            self.leases
+                .get(context)
+                .and_then(|deadlines| deadlines.get(&uuid).map(|d| *d <= now))
+                .unwrap_or(false)
+        }
+    }
+
+    /// Reserve a fresh UUID under `context` that expires after `ttl` unless renewed.
+    pub fn reserve_with_lease(
+        &self,
+        context: &str,
+        ttl: Duration,
+        base: u32,
+        max_retries: usize,
+    ) -> Result<Uuid, UuidPoolError> {
+        let uuid = random_uuid(self, context, base, max_retries, 0)?;
+        self.set_lease(context, uuid, Instant::now() + ttl);
+        Ok(uuid)
+    }
+
+    /// Extend the lease on `uuid` within `context` by `ttl` from now.
+    ///
+    /// Returns `false` if the UUID is not currently reserved (possibly because
+    /// its lease already lapsed and it was swept).
+    pub fn renew(&self, context: &str, uuid: Uuid, ttl: Duration) -> bool {
+        if !self.contains(context, uuid) {
+            return false;
+        }
+        self.set_lease(context, uuid, Instant::now() + ttl);
+        true
+    }
+
+    /// Drop every UUID in `context` whose lease deadline has passed, returning
+    /// how many were reclaimed.
+    pub fn sweep_expired(&self, context: &str) -> usize {
+        let now = Instant::now();
+        let expired: Vec<Uuid> = {
+            #[cfg(not(feature = "concurrent"))]
+            {
+                self.leases
+                    .lock()
+                    .get(context)
+                    .map(|deadlines| {
+                        deadlines
+                            .iter()
+                            .filter(|(_, d)| **d <= now)
+                            .map(|(uuid, _)| *uuid)
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            }
+            #[cfg(feature = "concurrent")]
+            {
+                self.leases
+                    .get(context)
+                    .map(|deadlines| {
+                        deadlines
+                            .iter()
+                            .filter(|(_, d)| **d <= now)
+                            .map(|(uuid, _)| *uuid)
+                            .collect()
+                    })
+                    .unwrap_or_default()
+            }
+        };
+
+        for uuid in &expired {
+            self.remove(context, *uuid);
+        }
+        expired.len()
+    }
+
+    /// Drop every expired lease across every context, returning the total
+    /// number of UUIDs reclaimed.
+    pub fn sweep_all(&self) -> usize {
+        let contexts: Vec<String> = {
+            #[cfg(not(feature = "concurrent"))]
+            {
+                self.leases.lock().keys().map(|k| k.to_string()).collect()
+            }
+            #[cfg(feature = "concurrent")]
+            {
+                self.leases.iter().map(|entry| entry.key().to_string()).collect()
+            }
+        };
+
+        contexts.iter().map(|context| self.sweep_expired(context)).sum()
     }
 }
 
-fn remove(context: &str, uuid: Uuid) -> bool {
-    match global_pool() {
-        #[cfg(not(feature = "concurrent"))]
-        GlobalUuidPool::SingleThreaded(pool) => {
-            let mut map = pool.lock();
-            let Some(set) = map.get_mut(context) else {
+impl Default for InMemoryStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UuidStore for InMemoryStore {
+    fn insert(&self, context: &str, uuid: Uuid) -> bool {
+        match &self.pool {
+            #[cfg(not(feature = "concurrent"))]
+            GlobalUuidPool::SingleThreaded(pool) => {
+                let mut map = pool.lock();
+                let key: ContextKey = Arc::from(context);
+                map.entry(key).or_insert_with(HashSet::new).insert(uuid)
+            }
+            #[cfg(feature = "concurrent")]
+            GlobalUuidPool::Concurrent(pool) => {
+                let key: ContextKey = Arc::from(context);
+
+                let set_ref = pool.entry(key).or_insert_with(DashSet::new);
+                set_ref.insert(uuid)
+            }
+        }
+    }
+
+    fn contains(&self, context: &str, uuid: Uuid) -> bool {
+        #[cfg(feature = "lease")]
+        {
+            if self.lease_expired(context, uuid, Instant::now()) {
+                self.remove(context, uuid);
                 return false;
-            };
+            }
+        }
+        match &self.pool {
+            #[cfg(not(feature = "concurrent"))]
+            GlobalUuidPool::SingleThreaded(pool) => {
+                let map = pool.lock();
+                map.get(context)
+                    .map(|set| set.contains(&uuid))
+                    .unwrap_or(false)
+            }
+            #[cfg(feature = "concurrent")]
+            GlobalUuidPool::Concurrent(pool) => pool
+                .get(context)
+                .map(|set_ref| set_ref.value().contains(&uuid))
+                .unwrap_or(false),
+        }
+    }
+
+    fn remove(&self, context: &str, uuid: Uuid) -> bool {
+        let removed = match &self.pool {
+            #[cfg(not(feature = "concurrent"))]
+            GlobalUuidPool::SingleThreaded(pool) => {
+                let mut map = pool.lock();
+                let Some(set) = map.get_mut(context) else {
+                    return false;
+                };
+
+                let removed = set.remove(&uuid);
+                if set.is_empty() {
+                    map.remove(context);
+                }
+                removed
+            }
+            #[cfg(feature = "concurrent")]
+            GlobalUuidPool::Concurrent(pool) => pool
+                .get(context)
+                .map(|set_ref| set_ref.value().remove(&uuid).is_some())
+                .unwrap_or(false),
+        };
+
+        if removed {
+            self.unbind_uuid(context, uuid);
+            #[cfg(feature = "lease")]
+            self.drop_lease(context, uuid);
+        }
+        removed
+    }
+
+    fn list(&self, context: &str) -> Vec<Uuid> {
+        #[cfg(feature = "lease")]
+        self.sweep_expired(context);
+        match &self.pool {
+            #[cfg(not(feature = "concurrent"))]
+            GlobalUuidPool::SingleThreaded(pool) => {
+                let map = pool.lock();
+                map.get(context)
+                    .map(|set| set.iter().copied().collect())
+                    .unwrap_or_default()
+            }
+            #[cfg(feature = "concurrent")]
+            GlobalUuidPool::Concurrent(pool) => pool
+                .get(context)
+                .map(|set_ref| set_ref.value().iter().map(|uuid| *uuid).collect())
+                .unwrap_or_default(),
+        }
+    }
 
-            let removed = set.remove(&uuid);
-            if set.is_empty() {
+    fn clear_context(&self, context: &str) {
+        match &self.pool {
+            #[cfg(not(feature = "concurrent"))]
+            GlobalUuidPool::SingleThreaded(pool) => {
+                let mut map = pool.lock();
                 map.remove(context);
             }
-            removed
+            #[cfg(feature = "concurrent")]
+            GlobalUuidPool::Concurrent(pool) => {
+                pool.remove(context);
+            }
+        }
+        #[cfg(not(feature = "concurrent"))]
+        {
+            self.names.lock().remove(context);
         }
         #[cfg(feature = "concurrent")]
-        GlobalUuidPool::Concurrent(pool) => pool
-            .get(context)
-            .map(|set_ref| set_ref.value().remove(&uuid).is_some())
-            .unwrap_or(false),
+        {
+            self.names.remove(context);
+        }
+        #[cfg(all(feature = "lease", not(feature = "concurrent")))]
+        {
+            self.leases.lock().remove(context);
+        }
+        #[cfg(all(feature = "lease", feature = "concurrent"))]
+        {
+            self.leases.remove(context);
+        }
     }
-}
 
-fn clear_context(context: &str) {
-    match global_pool() {
+    fn clear_all(&self) {
+        match &self.pool {
+            #[cfg(not(feature = "concurrent"))]
+            GlobalUuidPool::SingleThreaded(pool) => {
+                let mut map = pool.lock();
+                map.clear();
+            }
+            #[cfg(feature = "concurrent")]
+            GlobalUuidPool::Concurrent(pool) => {
+                pool.clear();
+            }
+        }
         #[cfg(not(feature = "concurrent"))]
-        GlobalUuidPool::SingleThreaded(pool) => {
-            let mut map = pool.lock();
-            map.remove(context);
+        {
+            self.names.lock().clear();
         }
         #[cfg(feature = "concurrent")]
-        GlobalUuidPool::Concurrent(pool) => {
-            pool.remove(context);
+        {
+            self.names.clear();
+        }
+        #[cfg(all(feature = "lease", not(feature = "concurrent")))]
+        {
+            self.leases.lock().clear();
         }
+        #[cfg(all(feature = "lease", feature = "concurrent"))]
+        {
+            self.leases.clear();
+        }
+    }
+
+    fn transfer_name(&self, context: &str, old: Uuid, new: Uuid) {
+        self.rebind_uuid(context, old, new);
     }
 }
 
-fn clear_all() {
-    match global_pool() {
-        #[cfg(not(feature = "concurrent"))]
-        GlobalUuidPool::SingleThreaded(pool) => {
-            let mut map = pool.lock();
-            map.clear();
+#[cfg(feature = "persistent")]
+impl InMemoryStore {
+    /// Collect every `(context, uuid)` pair currently reserved, so the live
+    /// state can be serialized without cloning whole sets behind a held lock.
+    fn entries(&self) -> Vec<(String, Uuid)> {
+        match &self.pool {
+            #[cfg(not(feature = "concurrent"))]
+            GlobalUuidPool::SingleThreaded(pool) => {
+                let map = pool.lock();
+                map.iter()
+                    .flat_map(|(context, set)| {
+                        set.iter().map(|uuid| (context.to_string(), *uuid))
+                    })
+                    .collect()
+            }
+            #[cfg(feature = "concurrent")]
+            GlobalUuidPool::Concurrent(pool) => pool
+                .iter()
+                .flat_map(|entry| {
+                    let context = entry.key().to_string();
+                    entry
+                        .value()
+                        .iter()
+                        .map(|uuid| (context.clone(), *uuid))
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
         }
-        #[cfg(feature = "concurrent")]
-        GlobalUuidPool::Concurrent(pool) => {
-            pool.clear();
+    }
+
+    /// Serialize the live state to a line-delimited `{context}\t{uuid}` form.
+    ///
+    /// Each reserved UUID becomes one line; the format is re-importable by
+    /// [`InMemoryStore::load`]. Contexts must not contain tab or newline
+    /// characters.
+    pub fn dump(&self, path: impl AsRef<std::path::Path>) -> Result<(), UuidPoolError> {
+        use std::io::Write;
+
+        let mut buf = String::new();
+        for (context, uuid) in self.entries() {
+            buf.push_str(&context);
+            buf.push('\t');
+            buf.push_str(&uuid.to_string());
+            buf.push('\n');
+        }
+
+        let mut file = std::fs::File::create(path)
+            .map_err(|e| UuidPoolError::PersistenceError(e.to_string()))?;
+        file.write_all(buf.as_bytes())
+            .map_err(|e| UuidPoolError::PersistenceError(e.to_string()))
+    }
+
+    /// Atomically copy the live state to `path` by writing a sibling temporary
+    /// file and renaming it into place, so a crash mid-write never leaves a
+    /// truncated snapshot.
+    pub fn snapshot(&self, path: impl AsRef<std::path::Path>) -> Result<(), UuidPoolError> {
+        let path = path.as_ref();
+        let tmp = path.with_extension("tmp");
+        self.dump(&tmp)?;
+        std::fs::rename(&tmp, path).map_err(|e| UuidPoolError::PersistenceError(e.to_string()))
+    }
+
+    /// Rehydrate the in-memory structures from a file produced by
+    /// [`InMemoryStore::dump`] or [`InMemoryStore::snapshot`].
+    pub fn load(&self, path: impl AsRef<std::path::Path>) -> Result<(), UuidPoolError> {
+        use std::io::BufRead;
+
+        let file = std::fs::File::open(path)
+            .map_err(|e| UuidPoolError::PersistenceError(e.to_string()))?;
+        let reader = std::io::BufReader::new(file);
+
+        for line in reader.lines() {
+            let line = line.map_err(|e| UuidPoolError::PersistenceError(e.to_string()))?;
+            if line.is_empty() {
+                continue;
+            }
+            let (context, raw_uuid) = line.split_once('\t').ok_or_else(|| {
+                UuidPoolError::PersistenceError(format!("Malformed entry: '{}'", line))
+            })?;
+            let uuid = Uuid::parse_str(raw_uuid)
+                .map_err(|e| UuidPoolError::PersistenceError(e.to_string()))?;
+            self.insert(context, uuid);
         }
+
+        Ok(())
     }
 }
 
+// Thread-safe process-global store used by the zero-argument `interface` calls.
+static GLOBAL_UUID_POOL: OnceLock<InMemoryStore> = OnceLock::new();
+
+pub(crate) fn default_store() -> &'static InMemoryStore {
+    GLOBAL_UUID_POOL.get_or_init(InMemoryStore::new)
+}
+
+fn make_uuid_with_base(base: u32) -> Uuid {
+    let mut bytes = [0u8; 16];
+    bytes[0..4].copy_from_slice(&base.to_be_bytes());
+    // Grab the thread-local RNG once and fill all twelve random bytes in a
+    // single call rather than sampling byte-by-byte.
+    rand::rng().fill_bytes(&mut bytes[4..]);
+    Uuid::new_v8(bytes)
+}
+
 pub(crate) fn random_uuid(
+    store: &dyn UuidStore,
     context: &str,
     base: u32,
     max_retries: usize,
     retry_count: usize,
 ) -> Result<Uuid, UuidPoolError> {
-    if retry_count >= max_retries {
-        return Err(UuidPoolError::FailedToGenerateUniqueUuidError(format!(
-            "Failed to generate unique UUID after {} attempts",
-            max_retries
-        )));
+    for _ in retry_count..max_retries {
+        let new_uuid = make_uuid_with_base(base);
+        if store.insert(context, new_uuid) {
+            return Ok(new_uuid);
+        }
     }
 
-    let new_uuid = make_uuid_with_base(base);
-
-    if try_insert(context, new_uuid) {
-        Ok(new_uuid)
-    } else {
-        random_uuid(context, base, max_retries, retry_count + 1)
-    }
+    Err(UuidPoolError::FailedToGenerateUniqueUuidError(format!(
+        "Failed to generate unique UUID after {} attempts",
+        max_retries
+    )))
 }
 
-pub(crate) fn add_uuid_to_pool(context: &str, uuid: &Uuid) -> Result<(), UuidPoolError> {
-    match contains(context, *uuid) {
+pub(crate) fn add_uuid_to_pool(
+    store: &dyn UuidStore,
+    context: &str,
+    uuid: &Uuid,
+) -> Result<(), UuidPoolError> {
+    match store.contains(context, *uuid) {
         true => {
             return Err(UuidPoolError::FailedToGenerateUniqueUuidError(format!(
                 "UUID already exists in pool for context '{}': {}",
@@ -167,7 +815,7 @@ pub(crate) fn add_uuid_to_pool(context: &str, uuid: &Uuid) -> Result<(), UuidPoo
             )));
         }
         false => {
-            if !try_insert(context, *uuid) {
+            if !store.insert(context, *uuid) {
                 return Err(UuidPoolError::FailedToGenerateUniqueUuidError(format!(
                     "UUID already exists in pool for context '{}': {}",
                     context, uuid
@@ -179,8 +827,12 @@ pub(crate) fn add_uuid_to_pool(context: &str, uuid: &Uuid) -> Result<(), UuidPoo
     Ok(())
 }
 
-pub(crate) fn remove_uuid_from_pool(context: &str, uuid: &Uuid) -> Result<(), UuidPoolError> {
-    match remove(context, *uuid) {
+pub(crate) fn remove_uuid_from_pool(
+    store: &dyn UuidStore,
+    context: &str,
+    uuid: &Uuid,
+) -> Result<(), UuidPoolError> {
+    match store.remove(context, *uuid) {
         true => Ok(()),
         false => Err(UuidPoolError::FailedToFindUuidInPoolError(
             "Failed to locate/remove UUID in pool".to_string(),
@@ -189,17 +841,22 @@ pub(crate) fn remove_uuid_from_pool(context: &str, uuid: &Uuid) -> Result<(), Uu
 }
 
 pub(crate) fn replace_uuid_in_pool(
+    store: &dyn UuidStore,
     context: &str,
     old_uuid: &Uuid,
     new_uuid: &Uuid,
 ) -> Result<(), UuidPoolError> {
-    if !contains(context, *old_uuid) {
-        add_uuid_to_pool(context, new_uuid)?
+    if !store.contains(context, *old_uuid) {
+        add_uuid_to_pool(store, context, new_uuid)?
     }
 
-    match remove(context, *old_uuid) {
+    // Keep the name index consistent: carry any binding on old_uuid over to
+    // new_uuid before old_uuid leaves the set (remove would otherwise unbind it).
+    store.transfer_name(context, *old_uuid, *new_uuid);
+
+    match store.remove(context, *old_uuid) {
         true => {
-            if !try_insert(context, *new_uuid) {
+            if !store.insert(context, *new_uuid) {
                 return Err(UuidPoolError::FailedToSetUuidInPoolError(format!(
                     "Failed to find UUID in pool for context '{}': {}",
                     context, old_uuid
@@ -217,26 +874,30 @@ pub(crate) fn replace_uuid_in_pool(
     Ok(())
 }
 
-pub(crate) fn get_context_uuids_from_pool(context: &str) -> Result<Vec<(String, Uuid)>, UuidPoolError> {
-    match global_pool() {
-        #[cfg(not(feature = "concurrent"))]
-        GlobalUuidPool::SingleThreaded(pool) => {
-            let map = pool.lock();
-            map.get(context).map(|set| set.clone().iter().map(|uuid| (context.to_string(), *uuid)).collect()).ok_or(UuidPoolError::FailedToFindUuidInPoolError(format!("Failed to find UUIDs in pool for context '{}'", context)))
-        }
-        #[cfg(feature = "concurrent")]
-        GlobalUuidPool::Concurrent(pool) => {
-            pool.get(context).map(|set| set.value().clone().iter().map(|uuid| (context.to_string(), *uuid)).collect()).ok_or(UuidPoolError::FailedToFindUuidInPoolError(format!("Failed to find UUIDs in pool for context '{}'", context)))
-        }
+pub(crate) fn get_context_uuids_from_pool(
+    store: &dyn UuidStore,
+    context: &str,
+) -> Result<Vec<(String, Uuid)>, UuidPoolError> {
+    let uuids = store.list(context);
+    if uuids.is_empty() {
+        return Err(UuidPoolError::FailedToFindUuidInPoolError(format!(
+            "Failed to find UUIDs in pool for context '{}'",
+            context
+        )));
     }
+
+    Ok(uuids
+        .into_iter()
+        .map(|uuid| (context.to_string(), uuid))
+        .collect())
 }
 
-pub(crate) fn drain_context(context: &str) -> Result<(), UuidPoolError> {
-    clear_context(context);
+pub(crate) fn drain_context(store: &dyn UuidStore, context: &str) -> Result<(), UuidPoolError> {
+    store.clear_context(context);
     Ok(())
 }
 
-pub(crate) fn drain_all_contexts() -> Result<(), UuidPoolError> {
-    clear_all();
+pub(crate) fn drain_all_contexts(store: &dyn UuidStore) -> Result<(), UuidPoolError> {
+    store.clear_all();
     Ok(())
 }